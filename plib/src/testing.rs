@@ -7,9 +7,12 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::fs;
 use std::io::Write;
 use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
 
+#[derive(Default)]
 pub struct TestPlan {
     pub cmd: String,
     pub args: Vec<String>,
@@ -17,6 +20,23 @@ pub struct TestPlan {
     pub expected_out: String,
     pub expected_err: String,
     pub expected_exit_code: i32,
+    /// Files written into the sandbox directory before the utility is launched.
+    pub input_files: Vec<(String, Vec<u8>)>,
+    /// Files the utility is expected to have produced in the sandbox, compared
+    /// byte-for-byte after it exits.
+    pub expected_files: Vec<(String, Vec<u8>)>,
+    /// Force the test to run in a fresh sandbox directory even when it declares
+    /// no `input_files`/`expected_files`. Utilities that write into the current
+    /// working directory (e.g. `csplit`) set this so their output files are
+    /// isolated and removed on drop.
+    pub sandbox: bool,
+    /// When set, `stdout` is compared against these raw bytes instead of going
+    /// through the lossy UTF-8 path, so binary or invalid-UTF-8 output can be
+    /// asserted exactly.
+    pub expected_out_bytes: Option<Vec<u8>>,
+    /// When set, `stderr` is compared against these raw bytes instead of going
+    /// through the lossy UTF-8 path.
+    pub expected_err_bytes: Option<Vec<u8>>,
 }
 
 fn run_test_base(plan: TestPlan) -> (TestPlan, Output) {
@@ -27,14 +47,40 @@ fn run_test_base(plan: TestPlan) -> (TestPlan, Output) {
         .unwrap() // Move up to the workspace root from the current package directory
         .join(relpath); // Adjust the path to the binary
 
+    // Tests that read or write files in the current working directory opt into
+    // a private sandbox by declaring `input_files`/`expected_files` or by
+    // setting `sandbox`. Such a
+    // test runs in its own temporary directory so that utilities writing into
+    // the current working directory (e.g. `csplit`) neither race with one
+    // another nor leave droppings behind on panic. The directory is removed
+    // when `sandbox` is dropped at the end of this function. Tests that pass
+    // relative asset paths as arguments keep running in the package directory.
+    let sandbox = if !plan.sandbox && plan.input_files.is_empty() && plan.expected_files.is_empty()
+    {
+        None
+    } else {
+        let dir = TempDir::new().expect("failed to create sandbox directory");
+        for (name, data) in &plan.input_files {
+            let path = dir.path().join(name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).expect("failed to create input file directory");
+            }
+            fs::write(path, data).expect("failed to write input file");
+        }
+        Some(dir)
+    };
+
     let mut command = Command::new(test_bin_path);
+    if let Some(sandbox) = &sandbox {
+        command.current_dir(sandbox.path());
+    }
     let mut child = command
         .args(&plan.args)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .expect("failed to spawn head");
+        .expect("failed to spawn utility");
 
     let stdin = child.stdin.as_mut().expect("failed to get stdin");
     stdin
@@ -42,17 +88,74 @@ fn run_test_base(plan: TestPlan) -> (TestPlan, Output) {
         .expect("failed to write to stdin");
 
     let output = child.wait_with_output().expect("failed to wait for child");
+
+    if let Some(sandbox) = &sandbox {
+        for (name, expected) in &plan.expected_files {
+            let actual = fs::read(sandbox.path().join(name))
+                .unwrap_or_else(|e| panic!("expected output file {name:?} missing: {e}"));
+            assert_eq!(&actual, expected, "contents of output file {name:?} differ");
+        }
+    }
+
     (plan, output)
 }
 
+/// Compare two byte slices exactly, panicking with a hex+ASCII dump of the
+/// first differing region when they do not match. `label` names the stream
+/// (e.g. `"stdout"`) in the failure message.
+fn assert_bytes_eq(actual: &[u8], expected: &[u8], label: &str) {
+    if actual == expected {
+        return;
+    }
+
+    let diff = actual
+        .iter()
+        .zip(expected.iter())
+        .position(|(a, e)| a != e)
+        .unwrap_or_else(|| actual.len().min(expected.len()));
+
+    // Show a window starting a little before the first difference.
+    let start = diff.saturating_sub(8);
+    panic!(
+        "{label} bytes differ at offset {diff} (actual {} bytes, expected {} bytes)\n\
+         actual:   {}\n\
+         expected: {}",
+        actual.len(),
+        expected.len(),
+        hex_ascii(actual, start),
+        hex_ascii(expected, start),
+    );
+}
+
+/// Render up to 16 bytes of `data` starting at `start` as `hex  |ascii|`.
+fn hex_ascii(data: &[u8], start: usize) -> String {
+    let window = &data[start.min(data.len())..(start + 16).min(data.len())];
+    let hex: Vec<String> = window.iter().map(|b| format!("{b:02x}")).collect();
+    let ascii: String = window
+        .iter()
+        .map(|&b| if (0x20..0x7f).contains(&b) { b as char } else { '.' })
+        .collect();
+    format!("{:<47}  |{}|", hex.join(" "), ascii)
+}
+
 pub fn run_test(plan: TestPlan) {
     let (plan, output) = run_test_base(plan);
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert_eq!(stdout, plan.expected_out);
+    match &plan.expected_out_bytes {
+        Some(expected) => assert_bytes_eq(&output.stdout, expected, "stdout"),
+        None => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            assert_eq!(stdout, plan.expected_out);
+        }
+    }
 
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    assert_eq!(stderr, plan.expected_err);
+    match &plan.expected_err_bytes {
+        Some(expected) => assert_bytes_eq(&output.stderr, expected, "stderr"),
+        None => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert_eq!(stderr, plan.expected_err);
+        }
+    }
 
     assert_eq!(output.status.code(), Some(plan.expected_exit_code));
     if plan.expected_exit_code == 0 {
@@ -0,0 +1,221 @@
+//
+// Copyright (c) 2024 Jeff Garzik
+//
+// This file is part of the posixutils-rs project covered under
+// the MIT License.  For the full license text, please see the LICENSE
+// file in the root directory of this project.
+// SPDX-License-Identifier: MIT
+//
+
+use clap::Parser;
+use gettextrs::{bind_textdomain_codeset, setlocale, textdomain, LocaleCategory};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+
+/// cut - cut out selected fields of each line of a file
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Select the listed bytes.
+    #[arg(short = 'b', value_name = "LIST")]
+    bytes: Option<String>,
+
+    /// Select the listed characters.
+    #[arg(short = 'c', value_name = "LIST")]
+    chars: Option<String>,
+
+    /// Select the listed fields.
+    #[arg(short = 'f', value_name = "LIST")]
+    fields: Option<String>,
+
+    /// Input field delimiter for -f (default: TAB).
+    #[arg(short = 'd', value_name = "DELIM")]
+    delimiter: Option<String>,
+
+    /// Suppress lines with no field delimiter characters in -f mode.
+    #[arg(short = 's')]
+    suppress: bool,
+
+    /// Select the complement of the listed bytes, characters, or fields.
+    #[arg(long)]
+    complement: bool,
+
+    /// Use STRING as the output delimiter (default: the input delimiter).
+    #[arg(long, value_name = "STRING")]
+    output_delimiter: Option<String>,
+
+    /// Files to read. With none, or when a file is '-', read standard input.
+    files: Vec<String>,
+}
+
+/// A parsed `LIST` of 1-based ranges, e.g. `1,3-5,7-`.
+struct Ranges(Vec<(usize, Option<usize>)>);
+
+impl Ranges {
+    fn parse(list: &str) -> Result<Ranges, String> {
+        let mut ranges = Vec::new();
+        for part in list.split(',') {
+            let range = match part.split_once('-') {
+                None => {
+                    let n = parse_index(part)?;
+                    (n, Some(n))
+                }
+                Some(("", "")) => return Err(format!("invalid range: '{part}'")),
+                Some(("", end)) => (1, Some(parse_index(end)?)),
+                Some((start, "")) => (parse_index(start)?, None),
+                Some((start, end)) => (parse_index(start)?, Some(parse_index(end)?)),
+            };
+            ranges.push(range);
+        }
+        Ok(Ranges(ranges))
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        self.0
+            .iter()
+            .any(|&(start, end)| index >= start && end.map_or(true, |e| index <= e))
+    }
+
+    /// The 1-based indices in `1..=count` this selection keeps, honoring the
+    /// `--complement` inversion, in ascending order.
+    fn selected(&self, count: usize, complement: bool) -> Vec<usize> {
+        (1..=count)
+            .filter(|&i| self.contains(i) != complement)
+            .collect()
+    }
+}
+
+fn parse_index(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) | Err(_) => Err(format!("invalid field value: '{s}'")),
+        Ok(n) => Ok(n),
+    }
+}
+
+enum Mode {
+    Bytes(Ranges),
+    Chars(Ranges),
+    Fields(Ranges),
+}
+
+fn cut_line(mode: &Mode, line: &str, args: &Args, out: &mut impl Write) -> io::Result<()> {
+    match mode {
+        Mode::Fields(ranges) => {
+            let delim = field_delim(args);
+            if !line.contains(delim) {
+                // A line without the delimiter is passed through unchanged,
+                // unless -s asks to drop such lines.
+                if !args.suppress {
+                    writeln!(out, "{line}")?;
+                }
+                return Ok(());
+            }
+            let parts: Vec<&str> = line.split(delim).collect();
+            let out_delim = output_delim(args, &delim.to_string());
+            let selected = ranges.selected(parts.len(), args.complement);
+            let chosen: Vec<&str> = selected.iter().map(|&i| parts[i - 1]).collect();
+            writeln!(out, "{}", chosen.join(&out_delim))?;
+        }
+        Mode::Chars(ranges) => {
+            let items: Vec<char> = line.chars().collect();
+            let selected = ranges.selected(items.len(), args.complement);
+            let out_delim = output_delim(args, "");
+            write_joined_runs(out, &selected, &out_delim, |i| {
+                items[i - 1].to_string().into_bytes()
+            })?;
+        }
+        Mode::Bytes(ranges) => {
+            let items = line.as_bytes();
+            let selected = ranges.selected(items.len(), args.complement);
+            let out_delim = output_delim(args, "");
+            // Emit the raw byte so -b can split a multibyte character exactly
+            // instead of round-tripping through lossy UTF-8. Lines are read as
+            // UTF-8, so input that is not valid UTF-8 is reported as an error.
+            write_joined_runs(out, &selected, &out_delim, |i| vec![items[i - 1]])?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the selected indices, inserting `out_delim` between non-contiguous
+/// runs so `-b`/`-c` slices can be joined by `--output-delimiter`.
+fn write_joined_runs(
+    out: &mut impl Write,
+    selected: &[usize],
+    out_delim: &str,
+    render: impl Fn(usize) -> Vec<u8>,
+) -> io::Result<()> {
+    let mut prev: Option<usize> = None;
+    for &i in selected {
+        if let Some(p) = prev {
+            if i != p + 1 {
+                out.write_all(out_delim.as_bytes())?;
+            }
+        }
+        out.write_all(&render(i))?;
+        prev = Some(i);
+    }
+    out.write_all(b"\n")
+}
+
+fn field_delim(args: &Args) -> char {
+    match &args.delimiter {
+        Some(d) => d.chars().next().unwrap_or('\t'),
+        None => '\t',
+    }
+}
+
+fn output_delim(args: &Args, default: &str) -> String {
+    args.output_delimiter
+        .clone()
+        .unwrap_or_else(|| default.to_string())
+}
+
+fn cut_reader<R: BufRead>(mode: &Mode, args: &Args, reader: R) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for line in reader.lines() {
+        let line = line?;
+        cut_line(mode, &line, args, &mut out)?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    setlocale(LocaleCategory::LcAll, "");
+    textdomain("posixutils-rs")?;
+    bind_textdomain_codeset("posixutils-rs", "UTF-8")?;
+
+    let args = Args::parse();
+
+    let mode = match (&args.bytes, &args.chars, &args.fields) {
+        (Some(list), None, None) => Mode::Bytes(Ranges::parse(list).map_err(io::Error::other)?),
+        (None, Some(list), None) => Mode::Chars(Ranges::parse(list).map_err(io::Error::other)?),
+        (None, None, Some(list)) => Mode::Fields(Ranges::parse(list).map_err(io::Error::other)?),
+        _ => {
+            eprintln!("cut: you must specify exactly one of -b, -c, or -f");
+            std::process::exit(1);
+        }
+    };
+
+    let paths: Vec<String> = if args.files.is_empty() {
+        vec![String::from("-")]
+    } else {
+        args.files.clone()
+    };
+
+    let mut exit_code = 0;
+    for path in &paths {
+        let result = if path == "-" {
+            cut_reader(&mode, &args, io::stdin().lock())
+        } else {
+            File::open(path).and_then(|f| cut_reader(&mode, &args, BufReader::new(f)))
+        };
+        if let Err(e) = result {
+            exit_code = 1;
+            eprintln!("cut: {path}: {e}");
+        }
+    }
+
+    std::process::exit(exit_code);
+}
@@ -0,0 +1,191 @@
+//
+// Copyright (c) 2024 Jeff Garzik
+//
+// This file is part of the posixutils-rs project covered under
+// the MIT License.  For the full license text, please see the LICENSE
+// file in the root directory of this project.
+// SPDX-License-Identifier: MIT
+//
+
+use clap::Parser;
+use gettextrs::{bind_textdomain_codeset, setlocale, textdomain, LocaleCategory};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+
+/// head - copy the first part of files
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Print the first NUM bytes of each file; with a leading '-', print all
+    /// but the last NUM bytes of each file.
+    #[arg(short = 'c', long, allow_hyphen_values = true)]
+    bytes: Option<String>,
+
+    /// Print the first NUM lines instead of the first 10; with a leading '-',
+    /// print all but the last NUM lines of each file.
+    #[arg(short = 'n', long, allow_hyphen_values = true)]
+    lines: Option<String>,
+
+    /// Files to read. With none, or when a file is '-', read standard input.
+    files: Vec<String>,
+}
+
+/// The amount to copy, and whether it is counted from the start or, when a
+/// leading '-' is given, from the end of the input.
+enum Count {
+    Leading(u64),
+    Trailing(u64),
+}
+
+impl Count {
+    fn parse(spec: &str) -> Result<Count, String> {
+        match spec.strip_prefix('-') {
+            Some(rest) => rest
+                .parse::<u64>()
+                .map(Count::Trailing)
+                .map_err(|_| format!("invalid number of units: '{spec}'")),
+            None => spec
+                .parse::<u64>()
+                .map(Count::Leading)
+                .map_err(|_| format!("invalid number of units: '{spec}'")),
+        }
+    }
+}
+
+/// Copy the first `count` lines (or all but the last, for `Count::Trailing`)
+/// from `input` to stdout, preserving bytes exactly.
+fn head_lines<R: Read>(mut input: R, count: &Count, out: &mut impl Write) -> io::Result<()> {
+    let mut reader = BufReader::new(&mut input);
+    match *count {
+        Count::Leading(n) => {
+            let mut remaining = n;
+            let mut record = Vec::new();
+            while remaining > 0 {
+                record.clear();
+                if read_line(&mut reader, &mut record)? == 0 {
+                    break;
+                }
+                out.write_all(&record)?;
+                remaining -= 1;
+            }
+        }
+        Count::Trailing(k) => {
+            // Keep a ring buffer of the last K records; whenever it grows past
+            // K, the oldest record can no longer be among the trailing K, so
+            // flush it. The K records still buffered at EOF are dropped.
+            let mut ring: VecDeque<Vec<u8>> = VecDeque::with_capacity(k as usize + 1);
+            loop {
+                let mut record = Vec::new();
+                if read_line(&mut reader, &mut record)? == 0 {
+                    break;
+                }
+                ring.push_back(record);
+                if ring.len() as u64 > k {
+                    out.write_all(&ring.pop_front().unwrap())?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Copy the first `count` bytes (or all but the last, for `Count::Trailing`)
+/// from `input` to stdout.
+fn head_bytes<R: Read>(mut input: R, count: &Count, out: &mut impl Write) -> io::Result<()> {
+    let mut reader = BufReader::new(&mut input);
+    match *count {
+        Count::Leading(n) => {
+            let mut remaining = n;
+            let mut buf = [0u8; 8192];
+            while remaining > 0 {
+                let want = remaining.min(buf.len() as u64) as usize;
+                let read = reader.read(&mut buf[..want])?;
+                if read == 0 {
+                    break;
+                }
+                out.write_all(&buf[..read])?;
+                remaining -= read as u64;
+            }
+        }
+        Count::Trailing(k) => {
+            // Maintain a rolling K-byte tail; bytes that fall out of the window
+            // are guaranteed not to be among the last K, so emit them as they
+            // leave. This streams without holding the whole input in memory.
+            let k = k as usize;
+            let mut ring: VecDeque<u8> = VecDeque::with_capacity(k + 1);
+            let mut buf = [0u8; 8192];
+            loop {
+                let read = reader.read(&mut buf)?;
+                if read == 0 {
+                    break;
+                }
+                for &b in &buf[..read] {
+                    ring.push_back(b);
+                    if ring.len() > k {
+                        out.write_all(&[ring.pop_front().unwrap()])?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn head_file(path: &str, bytes: &Option<Count>, lines: &Count) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    if path == "-" {
+        let stdin = io::stdin();
+        let handle = stdin.lock();
+        match bytes {
+            Some(count) => head_bytes(handle, count, &mut out),
+            None => head_lines(handle, lines, &mut out),
+        }
+    } else {
+        let file = File::open(path)?;
+        match bytes {
+            Some(count) => head_bytes(file, count, &mut out),
+            None => head_lines(file, lines, &mut out),
+        }
+    }
+}
+
+/// Read one line-terminated record (including the trailing newline, if any)
+/// into `buf`, returning the number of bytes read.
+fn read_line<R: io::BufRead>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize> {
+    reader.read_until(b'\n', buf)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    setlocale(LocaleCategory::LcAll, "");
+    textdomain("posixutils-rs")?;
+    bind_textdomain_codeset("posixutils-rs", "UTF-8")?;
+
+    let args = Args::parse();
+
+    let bytes = match &args.bytes {
+        Some(spec) => Some(Count::parse(spec).map_err(io::Error::other)?),
+        None => None,
+    };
+    let lines = match &args.lines {
+        Some(spec) => Count::parse(spec).map_err(io::Error::other)?,
+        None => Count::Leading(10),
+    };
+
+    let files = if args.files.is_empty() {
+        vec![String::from("-")]
+    } else {
+        args.files.clone()
+    };
+
+    let mut exit_code = 0;
+    for path in &files {
+        if let Err(e) = head_file(path, &bytes, &lines) {
+            exit_code = 1;
+            eprintln!("head: {path}: {e}");
+        }
+    }
+
+    std::process::exit(exit_code);
+}
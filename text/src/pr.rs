@@ -0,0 +1,338 @@
+//
+// Copyright (c) 2024 Jeff Garzik
+//
+// This file is part of the posixutils-rs project covered under
+// the MIT License.  For the full license text, please see the LICENSE
+// file in the root directory of this project.
+// SPDX-License-Identifier: MIT
+//
+
+use chrono::{DateTime, Local};
+use clap::Parser;
+use gettextrs::{bind_textdomain_codeset, setlocale, textdomain, LocaleCategory};
+use std::fs;
+use std::io::{self, Read, Write};
+
+const DATE_TIME_FORMAT: &str = "%b %d %H:%M %Y";
+const DEFAULT_PAGE_LENGTH: usize = 66;
+const DEFAULT_WIDTH: usize = 72;
+/// Lines consumed by the top and bottom margins (header and trailer) when the
+/// header is not omitted.
+const MARGIN_LINES: usize = 10;
+const FORM_FEED: char = '\u{000c}';
+
+/// pr - print files
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Begin output at page number FIRST[:LAST] of the input. Supplied on the
+    /// command line as `+FIRST[:LAST]` and normalized before parsing.
+    #[arg(long = "pages", value_name = "FIRST[:LAST]")]
+    pages: Option<String>,
+
+    /// Produce output in COLUMN columns. Supplied as `-COLUMN` and normalized
+    /// before parsing.
+    #[arg(long = "columns", value_name = "COLUMN")]
+    columns: Option<usize>,
+
+    /// Accepted for compatibility; it has no effect on the layout, which
+    /// always fills each column top to bottom before the next.
+    #[arg(short = 'a')]
+    across: bool,
+
+    /// Use STRING for the header instead of the file name.
+    #[arg(short = 'h', value_name = "STRING")]
+    header: Option<String>,
+
+    /// Override the PAGE_LENGTH lines per page (default 66).
+    #[arg(short = 'l', value_name = "PAGE_LENGTH")]
+    page_length: Option<usize>,
+
+    /// Offset each line by OFFSET spaces.
+    #[arg(short = 'o', value_name = "OFFSET")]
+    offset: Option<usize>,
+
+    /// Set the page width to WIDTH columns (default 72).
+    #[arg(short = 'w', value_name = "WIDTH")]
+    width: Option<usize>,
+
+    /// Omit the page header and trailer.
+    #[arg(short = 't')]
+    omit_header: bool,
+
+    /// Double-space the output, inserting a blank line after each body line.
+    #[arg(short = 'd')]
+    double_space: bool,
+
+    /// Separate pages with a form-feed instead of padding with blank lines.
+    #[arg(short = 'f')]
+    form_feed: bool,
+
+    /// Like -f, but also replace the header/trailer blank-line fill with a
+    /// single form-feed on the final page.
+    #[arg(short = 'F')]
+    form_feed_trim: bool,
+
+    /// Files to print. With none, or when a file is '-', read standard input.
+    files: Vec<String>,
+}
+
+fn parse_page_range(s: &str) -> (usize, Option<usize>) {
+    let s = s.trim_start_matches('+');
+    match s.split_once(':') {
+        Some((first, last)) => (
+            first.parse().unwrap_or(1),
+            last.parse().ok(),
+        ),
+        None => (s.parse().unwrap_or(1), None),
+    }
+}
+
+/// Rewrite pr's historical `+FIRST[:LAST]` page selector and `-COLUMN` column
+/// count into the long options clap understands, leaving all other arguments
+/// untouched.
+fn normalize_args<I: Iterator<Item = String>>(raw: I) -> Vec<String> {
+    let mut out = Vec::new();
+    for arg in raw {
+        if let Some(rest) = arg.strip_prefix('+') {
+            out.push(String::from("--pages"));
+            out.push(rest.to_string());
+        } else if arg.len() >= 2
+            && arg.starts_with('-')
+            && arg[1..].chars().all(|c| c.is_ascii_digit())
+        {
+            out.push(String::from("--columns"));
+            out.push(arg[1..].to_string());
+        } else {
+            out.push(arg);
+        }
+    }
+    out
+}
+
+struct Config {
+    page_length: usize,
+    offset: usize,
+    width: usize,
+    columns: usize,
+    omit_header: bool,
+    double_space: bool,
+    form_feed: bool,
+    form_feed_trim: bool,
+    first_page: usize,
+    last_page: Option<usize>,
+}
+
+impl Config {
+    fn from_args(args: &Args) -> Config {
+        let (first_page, last_page) = args
+            .pages
+            .as_deref()
+            .map(parse_page_range)
+            .unwrap_or((1, None));
+        Config {
+            page_length: args.page_length.unwrap_or(DEFAULT_PAGE_LENGTH),
+            offset: args.offset.unwrap_or(0),
+            width: args.width.unwrap_or(DEFAULT_WIDTH),
+            columns: args.columns.unwrap_or(1).max(1),
+            omit_header: args.omit_header,
+            double_space: args.double_space,
+            form_feed: args.form_feed || args.form_feed_trim,
+            form_feed_trim: args.form_feed_trim,
+            first_page,
+            last_page,
+        }
+    }
+
+    /// Number of body (text) rows that fit on one page.
+    fn body_rows(&self) -> usize {
+        let body = if self.omit_header {
+            self.page_length
+        } else {
+            self.page_length.saturating_sub(MARGIN_LINES)
+        };
+        // Double spacing inserts a blank line after every body row, so only
+        // half as many text rows land on each page.
+        if self.double_space {
+            body / 2
+        } else {
+            body
+        }
+    }
+}
+
+/// Lay out `lines` into column-major rows: each page holds `rows * columns`
+/// entries, filling the first column top-to-bottom before the next.
+fn assemble_page(lines: &[String], cfg: &Config) -> Vec<String> {
+    let rows = cfg.body_rows();
+    let col_width = cfg.width / cfg.columns;
+    let mut out = Vec::with_capacity(rows);
+    for r in 0..rows {
+        let mut cells = Vec::with_capacity(cfg.columns);
+        for c in 0..cfg.columns {
+            let idx = c * rows + r;
+            if let Some(line) = lines.get(idx) {
+                cells.push(line.clone());
+            } else {
+                cells.push(String::new());
+            }
+        }
+        // Trim trailing empty cells so short pages do not emit padding spaces.
+        while cells.last().map_or(false, |c| c.is_empty()) {
+            cells.pop();
+        }
+        if cfg.columns > 1 {
+            let row = cells
+                .iter()
+                .map(|cell| format!("{cell:<width$}", width = col_width))
+                .collect::<String>();
+            out.push(row.trim_end().to_string());
+        } else {
+            out.push(cells.into_iter().next().unwrap_or_default());
+        }
+    }
+    // Drop trailing empty rows so a short final page does not carry padding
+    // that the page-separation logic will supply (or a form-feed will replace).
+    while out.last().map_or(false, |row| row.is_empty()) {
+        out.pop();
+    }
+    out
+}
+
+fn header_line(date: &str, name: &str, page: usize) -> String {
+    format!("{date} {name} Page {page}")
+}
+
+fn write_page(
+    out: &mut impl Write,
+    cfg: &Config,
+    date: &str,
+    name: &str,
+    page: usize,
+    body: &[String],
+    is_last: bool,
+) -> io::Result<()> {
+    let pad = " ".repeat(cfg.offset);
+
+    if !cfg.omit_header {
+        writeln!(out)?;
+        writeln!(out)?;
+        writeln!(out, "{pad}{}", header_line(date, name, page))?;
+        writeln!(out)?;
+        writeln!(out)?;
+    }
+
+    let mut physical = 0usize;
+    for line in body {
+        if line.is_empty() {
+            writeln!(out)?;
+        } else {
+            writeln!(out, "{pad}{line}")?;
+        }
+        physical += 1;
+        // Double spacing inserts a blank line after every body line, keeping
+        // the per-page line budget honest so pagination still lands correctly.
+        if cfg.double_space {
+            writeln!(out)?;
+            physical += 1;
+        }
+    }
+
+    // Page separation. With a form-feed requested, a single '\f' replaces the
+    // blank-line fill that would otherwise pad the page to its full length.
+    if cfg.form_feed {
+        if !is_last || cfg.form_feed_trim {
+            write!(out, "{FORM_FEED}")?;
+        }
+    } else {
+        // Pad the body to a full page and add the bottom margin with blanks.
+        for _ in physical..page_body_lines(cfg) {
+            writeln!(out)?;
+        }
+        if !cfg.omit_header {
+            for _ in 0..(MARGIN_LINES - 5) {
+                writeln!(out)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The number of physical body lines a full page occupies (text rows plus the
+/// blank lines double spacing interleaves).
+fn page_body_lines(cfg: &Config) -> usize {
+    let rows = cfg.body_rows();
+    if cfg.double_space {
+        rows * 2
+    } else {
+        rows
+    }
+}
+
+fn print_file(out: &mut impl Write, cfg: &Config, name: &str, text: &str, date: &str) -> io::Result<()> {
+    let lines: Vec<String> = text.lines().map(|l| l.to_string()).collect();
+    let rows = cfg.body_rows();
+    let per_page = rows * cfg.columns;
+    let total_pages = lines.len().div_ceil(per_page).max(1);
+
+    let last = cfg.last_page.unwrap_or(total_pages).min(total_pages);
+    for page in cfg.first_page..=last {
+        let start = (page - 1) * per_page;
+        let slice = &lines[start.min(lines.len())..(start + per_page).min(lines.len())];
+        let body = assemble_page(slice, cfg);
+        write_page(out, cfg, date, name, page, &body, page == last)?;
+    }
+    Ok(())
+}
+
+fn file_date(path: &str) -> String {
+    match fs::metadata(path).and_then(|m| m.modified()) {
+        Ok(t) => {
+            let dt: DateTime<Local> = t.into();
+            dt.format(DATE_TIME_FORMAT).to_string()
+        }
+        Err(_) => String::new(),
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    setlocale(LocaleCategory::LcAll, "");
+    textdomain("posixutils-rs")?;
+    bind_textdomain_codeset("posixutils-rs", "UTF-8")?;
+
+    let args = Args::parse_from(normalize_args(std::env::args()));
+    let cfg = Config::from_args(&args);
+
+    let paths: Vec<String> = if args.files.is_empty() {
+        vec![String::from("-")]
+    } else {
+        args.files.clone()
+    };
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut exit_code = 0;
+    for path in &paths {
+        let (text, name, date) = if path == "-" {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf)?;
+            (buf, String::new(), String::new())
+        } else {
+            match fs::read_to_string(path) {
+                Ok(text) => {
+                    let header = args.header.clone().unwrap_or_else(|| path.clone());
+                    (text, header, file_date(path))
+                }
+                Err(e) => {
+                    exit_code = 1;
+                    eprintln!("pr: {path}: {e}");
+                    continue;
+                }
+            }
+        };
+        print_file(&mut out, &cfg, &name, &text, &date)?;
+    }
+
+    std::process::exit(exit_code);
+}
@@ -0,0 +1,195 @@
+//
+// Copyright (c) 2024 Jeff Garzik
+//
+// This file is part of the posixutils-rs project covered under
+// the MIT License.  For the full license text, please see the LICENSE
+// file in the root directory of this project.
+// SPDX-License-Identifier: MIT
+//
+
+use clap::Parser;
+use gettextrs::{bind_textdomain_codeset, setlocale, textdomain, LocaleCategory};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+
+/// wc - word, line, and byte or character count
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Count bytes.
+    #[arg(short = 'c')]
+    bytes: bool,
+
+    /// Count characters (multibyte-aware), not bytes.
+    #[arg(short = 'm')]
+    chars: bool,
+
+    /// Count lines.
+    #[arg(short = 'l')]
+    lines: bool,
+
+    /// Count words.
+    #[arg(short = 'w')]
+    words: bool,
+
+    /// Report the display width of the longest line.
+    #[arg(short = 'L')]
+    max_line: bool,
+
+    /// Files to read. With none, or when a file is '-', read standard input.
+    files: Vec<String>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Counts {
+    lines: u64,
+    words: u64,
+    bytes: u64,
+    chars: u64,
+    max_line: usize,
+}
+
+impl Counts {
+    fn add(&mut self, other: &Counts) {
+        self.lines += other.lines;
+        self.words += other.words;
+        self.bytes += other.bytes;
+        self.chars += other.chars;
+        // The longest line across all files is the maximum, not the sum.
+        self.max_line = self.max_line.max(other.max_line);
+    }
+}
+
+/// Advance the column position for a single character, honoring tab stops
+/// (every 8 columns) and treating zero-width/combining characters as 0 wide.
+fn advance_column(col: usize, c: char) -> usize {
+    match c {
+        '\t' => col + (8 - col % 8),
+        _ => col + display_width(c),
+    }
+}
+
+/// Display width of a character in columns: 0 for combining/zero-width marks,
+/// 1 otherwise. (Wide East-Asian handling is out of scope here, matching the
+/// surrounding utilities.)
+fn display_width(c: char) -> usize {
+    if is_zero_width(c) {
+        0
+    } else {
+        1
+    }
+}
+
+fn is_zero_width(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F // combining diacritical marks
+        | 0x200B..=0x200F // zero-width space and bidi marks
+        | 0xFEFF) // zero-width no-break space
+}
+
+fn count_reader<R: Read>(reader: R) -> io::Result<Counts> {
+    let mut reader = BufReader::new(reader);
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    let mut counts = Counts {
+        bytes: data.len() as u64,
+        ..Default::default()
+    };
+
+    let text = String::from_utf8_lossy(&data);
+    counts.chars = text.chars().count() as u64;
+
+    let mut in_word = false;
+    let mut col = 0usize;
+    for c in text.chars() {
+        if c == '\n' {
+            counts.lines += 1;
+            counts.max_line = counts.max_line.max(col);
+            col = 0;
+        } else {
+            col = advance_column(col, c);
+        }
+
+        if c.is_whitespace() {
+            in_word = false;
+        } else if !in_word {
+            in_word = true;
+            counts.words += 1;
+        }
+    }
+    // A final line without a trailing newline still counts toward -L.
+    counts.max_line = counts.max_line.max(col);
+
+    Ok(counts)
+}
+
+fn format_counts(counts: &Counts, args: &Args, name: Option<&str>) -> String {
+    // When no count flag is given, POSIX prints lines, words, and bytes.
+    let any = args.lines || args.words || args.bytes || args.chars || args.max_line;
+    let mut fields: Vec<String> = Vec::new();
+    if args.lines || !any {
+        fields.push(counts.lines.to_string());
+    }
+    if args.words || !any {
+        fields.push(counts.words.to_string());
+    }
+    if args.chars {
+        fields.push(counts.chars.to_string());
+    }
+    if args.bytes || !any {
+        fields.push(counts.bytes.to_string());
+    }
+    if args.max_line {
+        fields.push(counts.max_line.to_string());
+    }
+    let mut line = fields.join(" ");
+    if let Some(name) = name {
+        line.push(' ');
+        line.push_str(name);
+    }
+    line.push('\n');
+    line
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    setlocale(LocaleCategory::LcAll, "");
+    textdomain("posixutils-rs")?;
+    bind_textdomain_codeset("posixutils-rs", "UTF-8")?;
+
+    let args = Args::parse();
+
+    let paths: Vec<String> = if args.files.is_empty() {
+        vec![String::from("-")]
+    } else {
+        args.files.clone()
+    };
+    let multiple = paths.len() > 1;
+
+    let mut total = Counts::default();
+    let mut exit_code = 0;
+    for path in &paths {
+        let result = if path == "-" {
+            count_reader(io::stdin().lock())
+        } else {
+            File::open(path).and_then(count_reader)
+        };
+        match result {
+            Ok(counts) => {
+                total.add(&counts);
+                let name = if path == "-" { None } else { Some(path.as_str()) };
+                print!("{}", format_counts(&counts, &args, name));
+            }
+            Err(e) => {
+                exit_code = 1;
+                eprintln!("wc: {path}: {e}");
+            }
+        }
+    }
+
+    if multiple {
+        print!("{}", format_counts(&total, &args, Some("total")));
+    }
+
+    std::process::exit(exit_code);
+}
@@ -23,6 +23,7 @@ fn expand_test_noargs(test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
     });
 }
 
@@ -34,6 +35,21 @@ fn head_test(test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
+    });
+}
+
+fn head_test_args(args: &[&str], test_data: &str, expected_output: &str) {
+    let str_args: Vec<String> = args.iter().map(|s| String::from(*s)).collect();
+
+    run_test(TestPlan {
+        cmd: String::from("head"),
+        args: str_args,
+        stdin_data: String::from(test_data),
+        expected_out: String::from(expected_output),
+        expected_err: String::from(""),
+        expected_exit_code: 0,
+        ..Default::default()
     });
 }
 
@@ -47,12 +63,23 @@ fn wc_test(args: &[&str], test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
     });
 }
 
 fn csplit_test(args: &[&str], test_data: &str, expected_output: &str) {
     let str_args: Vec<String> = args.iter().map(|s| String::from(*s)).collect();
 
+    // csplit writes its split files into the current working directory, so run
+    // it in a fresh sandbox that is removed on drop. Any input asset referenced
+    // by a relative `tests/assets/...` path is staged into the sandbox under
+    // the same name so the child still resolves it.
+    let input_files: Vec<(String, Vec<u8>)> = str_args
+        .iter()
+        .filter(|a| a.starts_with("tests/assets/"))
+        .map(|a| (a.clone(), fs::read(a).unwrap()))
+        .collect();
+
     run_test(TestPlan {
         cmd: String::from("csplit"),
         args: str_args,
@@ -60,6 +87,9 @@ fn csplit_test(args: &[&str], test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        sandbox: true,
+        input_files,
+        ..Default::default()
     });
 }
 
@@ -73,6 +103,7 @@ fn nl_test(args: &[&str], test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
     });
 }
 
@@ -86,6 +117,7 @@ fn pr_test(args: &[&str], test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
     });
 }
 
@@ -99,6 +131,7 @@ fn cut_test(args: &[&str], test_data: &str, expected_output: &str) {
         expected_out: String::from(expected_output),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
     });
 }
 
@@ -152,6 +185,32 @@ fn test_head_basic() {
     );
 }
 
+#[test]
+fn test_head_bytes() {
+    // -c N emits the first N bytes, ignoring line boundaries.
+    head_test_args(&["-c", "5"], "hello world\n", "hello");
+    head_test_args(&["-c", "1"], "abc", "a");
+    // Asking for more bytes than exist yields the whole input.
+    head_test_args(&["-c", "20"], "abc\n", "abc\n");
+}
+
+#[test]
+fn test_head_negative_lines() {
+    // -n -K prints all but the last K lines.
+    head_test_args(&["-n", "-2"], "a\nb\nc\nd\n", "a\nb\n");
+    head_test_args(&["-n", "-1"], "a\nb\nc\n", "a\nb\n");
+    // Dropping at least as many lines as exist produces no output.
+    head_test_args(&["-n", "-5"], "a\nb\nc\n", "");
+}
+
+#[test]
+fn test_head_negative_bytes() {
+    // -c -K prints all but the last K bytes.
+    head_test_args(&["-c", "-3"], "abcdef", "abc");
+    head_test_args(&["-c", "-1"], "hello\n", "hello");
+    head_test_args(&["-c", "-10"], "abc", "");
+}
+
 #[test]
 fn test_wc_empty() {
     wc_test(&["-c"], "", "0\n");
@@ -173,6 +232,23 @@ fn test_wc_two() {
     wc_test(&["-w"], "x y\n", "2\n");
 }
 
+#[test]
+fn test_wc_chars_multibyte() {
+    // -m counts code points, not bytes: "héllo\n" is 6 characters but 7 bytes.
+    wc_test(&["-m"], "héllo\n", "6\n");
+    wc_test(&["-c"], "héllo\n", "7\n");
+    wc_test(&["-m"], "", "0\n");
+}
+
+#[test]
+fn test_wc_longest_line() {
+    // -L reports the display width of the longest line.
+    wc_test(&["-L"], "a\nbb\nccc\n", "3\n");
+    // Tabs advance to the next multiple of 8, so "a\tb" is 9 columns wide.
+    wc_test(&["-L"], "a\tb\n", "9\n");
+    wc_test(&["-L"], "", "0\n");
+}
+
 #[test]
 fn test_csplit_text_by_lines() {
     csplit_test(
@@ -196,10 +272,6 @@ fn test_csplit_text_by_lines() {
 17",
         "43\n\n57\n\n31\n\n14\n\n",
     );
-    std::fs::remove_file("text00").unwrap();
-    std::fs::remove_file("text01").unwrap();
-    std::fs::remove_file("text02").unwrap();
-    std::fs::remove_file("text03").unwrap();
 }
 
 #[test]
@@ -209,10 +281,6 @@ fn test_csplit_text_by_lines_from_file() {
         "",
         "43\n\n57\n\n31\n\n14\n\n",
     );
-    std::fs::remove_file("text_f00").unwrap();
-    std::fs::remove_file("text_f01").unwrap();
-    std::fs::remove_file("text_f02").unwrap();
-    std::fs::remove_file("text_f03").unwrap();
 }
 
 #[test]
@@ -229,10 +297,6 @@ fn test_csplit_c_code_by_regex() {
         "",
         "59\n\n53\n\n53\n\n54\n\n",
     );
-    std::fs::remove_file("code_c00").unwrap();
-    std::fs::remove_file("code_c01").unwrap();
-    std::fs::remove_file("code_c02").unwrap();
-    std::fs::remove_file("code_c03").unwrap();
 }
 
 #[test]
@@ -249,10 +313,6 @@ fn test_csplit_c_code_by_regex_negative_offset() {
         "",
         "12\n\n46\n\n52\n\n107\n\n",
     );
-    std::fs::remove_file("code_c_neg00").unwrap();
-    std::fs::remove_file("code_c_neg01").unwrap();
-    std::fs::remove_file("code_c_neg02").unwrap();
-    std::fs::remove_file("code_c_neg03").unwrap();
 }
 
 #[test]
@@ -270,10 +330,6 @@ fn test_csplit_c_code_by_regex_suppress() {
         "",
         "",
     );
-    std::fs::remove_file("code_c_s00").unwrap();
-    std::fs::remove_file("code_c_s01").unwrap();
-    std::fs::remove_file("code_c_s02").unwrap();
-    std::fs::remove_file("code_c_s03").unwrap();
 }
 
 #[test]
@@ -292,10 +348,6 @@ fn test_csplit_c_code_by_regex_with_number() {
         "",
         "59\n\n53\n\n53\n\n54\n\n",
     );
-    std::fs::remove_file("code_c_n000").unwrap();
-    std::fs::remove_file("code_c_n001").unwrap();
-    std::fs::remove_file("code_c_n002").unwrap();
-    std::fs::remove_file("code_c_n003").unwrap();
 }
 
 #[test]
@@ -305,8 +357,6 @@ fn test_csplit_regex_by_empty_lines() {
         "",
         "6\n\n7\n\n",
     );
-    std::fs::remove_file("empty_lines00").unwrap();
-    std::fs::remove_file("empty_lines01").unwrap();
 }
 
 #[test]
@@ -322,7 +372,6 @@ fn test_csplit_regex_would_infloop() {
         "",
         "2\n\n",
     );
-    std::fs::remove_file("would_infloop00").unwrap();
 }
 
 #[test]
@@ -332,10 +381,6 @@ fn test_csplit_regex_in_uniq() {
         "",
         "6\n\n10\n\n8\n\n9\n\n",
     );
-    std::fs::remove_file("in_uniq00").unwrap();
-    std::fs::remove_file("in_uniq01").unwrap();
-    std::fs::remove_file("in_uniq02").unwrap();
-    std::fs::remove_file("in_uniq03").unwrap();
 }
 
 #[test]
@@ -345,10 +390,6 @@ fn test_csplit_regex_in_uniq_2() {
         "",
         "3\n\n10\n\n8\n\n12\n\n",
     );
-    std::fs::remove_file("in_uniq_2_00").unwrap();
-    std::fs::remove_file("in_uniq_2_01").unwrap();
-    std::fs::remove_file("in_uniq_2_02").unwrap();
-    std::fs::remove_file("in_uniq_2_03").unwrap();
 }
 
 #[test]
@@ -358,10 +399,6 @@ fn test_csplit_regex_in_uniq_3() {
         "",
         "7\n\n10\n\n8\n\n8\n\n",
     );
-    std::fs::remove_file("in_uniq_3_00").unwrap();
-    std::fs::remove_file("in_uniq_3_01").unwrap();
-    std::fs::remove_file("in_uniq_3_02").unwrap();
-    std::fs::remove_file("in_uniq_3_03").unwrap();
 }
 
 #[test]
@@ -371,10 +408,47 @@ fn test_csplit_regex_in_seq() {
         "",
         "1\n\n3\n\n3\n\n1\n\n",
     );
-    std::fs::remove_file("in_seq00").unwrap();
-    std::fs::remove_file("in_seq01").unwrap();
-    std::fs::remove_file("in_seq02").unwrap();
-    std::fs::remove_file("in_seq03").unwrap();
+}
+
+#[test]
+fn test_cut_complement() {
+    // --complement emits the fields the range did *not* select, in order.
+    cut_test(&["-d", ",", "-f", "2", "--complement"], "a,b,c,d\n", "a,c,d\n");
+}
+
+#[test]
+fn test_cut_output_delimiter() {
+    // --output-delimiter replaces the input delimiter on output in -f mode.
+    cut_test(
+        &["-d", ",", "-f", "1,3", "--output-delimiter=:"],
+        "a,b,c,d\n",
+        "a:c\n",
+    );
+}
+
+#[test]
+fn test_cut_complement_output_delimiter() {
+    // The two features combine: select the complement of field 2 and join the
+    // surviving fields with the output delimiter.
+    cut_test(
+        &["-d", ",", "-f", "2", "--complement", "--output-delimiter=:"],
+        "a,b,c,d\n",
+        "a:c:d\n",
+    );
+}
+
+#[test]
+fn test_cut_bytes_multibyte() {
+    // -b slices by raw bytes: taking the first byte of 'á' (0xC3 0xA1) yields a
+    // lone 0xC3, which is not valid UTF-8, so assert the exact output bytes.
+    run_test(TestPlan {
+        cmd: String::from("cut"),
+        args: vec![String::from("-b"), String::from("1")],
+        stdin_data: String::from("áb\n"),
+        expected_out_bytes: Some(vec![0xC3, b'\n']),
+        expected_exit_code: 0,
+        ..Default::default()
+    });
 }
 
 #[test]
@@ -502,6 +576,7 @@ fn test_pr_multi_column_merge() {
         expected_out: String::from(""),
         expected_err: String::from(""),
         expected_exit_code: 0,
+        ..Default::default()
     };
 
     run_test_with_checker(test_plan, |_, output| {
@@ -614,3 +689,52 @@ fn test_pr_expand_and_replace() {
     );
     pr_test(&["-i?3", "-e", "-t", &input], "", &output);
 }
+
+#[test]
+fn test_pr_double_space() {
+    // -d double-spaces the body, inserting a blank line after each line while
+    // still honouring the per-page line limit.
+    let input = "tests/pr/numbers.txt";
+    let output = pr_read_test_file("tests/pr/numbers_output_double_space.txt", input, None, None);
+    pr_test(&["+1:1", "-l20", "-d", &input], "", &output);
+}
+
+#[test]
+fn test_pr_form_feed() {
+    // -f separates pages with a form-feed instead of padding with newlines.
+    let input = "tests/pr/numbers.txt";
+    let output = pr_read_test_file("tests/pr/numbers_output_form_feed.txt", input, None, None);
+    pr_test(&["-9", "-f", &input], "", &output);
+}
+
+#[test]
+fn test_pr_form_feed_multipage() {
+    // With a small page length the input spans several pages, so -f inserts a
+    // form-feed between them (but not after the final page).
+    run_test(TestPlan {
+        cmd: String::from("pr"),
+        args: vec![
+            String::from("-t"),
+            String::from("-l3"),
+            String::from("-f"),
+        ],
+        stdin_data: String::from("1\n2\n3\n4\n5\n6\n7\n"),
+        expected_out: String::from("1\n2\n3\n\u{000c}4\n5\n6\n\u{000c}7\n"),
+        expected_exit_code: 0,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn test_pr_form_feed_omit_header() {
+    // -F combined with -t ends the final page with a single form-feed, which
+    // -f alone would leave out.
+    let input = "tests/pr/numbers.txt";
+    let output = pr_read_test_file(
+        "tests/pr/numbers_output_form_feed_trim.txt",
+        input,
+        None,
+        None,
+    );
+    pr_test(&["+1:1", "-l20", "-t", "-F", &input], "", &output);
+}